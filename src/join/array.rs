@@ -0,0 +1,74 @@
+use core::array;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::future::FusedFuture;
+
+use super::Join;
+use crate::utils::MaybeDone;
+
+impl<Fut, const N: usize> Join for [Fut; N]
+where
+    Fut: Future,
+{
+    type Output = [Fut::Output; N];
+    type Future = JoinArray<Fut, N>;
+
+    fn join(self) -> Self::Future {
+        JoinArray {
+            elems: self.map(MaybeDone::new),
+        }
+    }
+}
+
+/// A future that waits for an array of futures to complete.
+///
+/// This `struct` is created by the [`join`](Join::join) method on arrays of
+/// [`Future`]s.
+pub struct JoinArray<Fut: Future, const N: usize> {
+    elems: [MaybeDone<Fut>; N],
+}
+
+impl<Fut: Future, const N: usize> fmt::Debug for JoinArray<Fut, N>
+where
+    Fut: fmt::Debug,
+    Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinArray").field("elems", &self.elems).finish()
+    }
+}
+
+impl<Fut: Future, const N: usize> Future for JoinArray<Fut, N> {
+    type Output = [Fut::Output; N];
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut all_done = true;
+
+        let this = unsafe { self.get_unchecked_mut() };
+        for elem in this.elems.iter_mut() {
+            let elem = unsafe { Pin::new_unchecked(elem) };
+            if elem.poll(cx).is_pending() {
+                all_done = false;
+            }
+        }
+
+        if all_done {
+            let out = array::from_fn(|i| {
+                let elem = unsafe { Pin::new_unchecked(&mut this.elems[i]) };
+                elem.take().unwrap()
+            });
+            Poll::Ready(out)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<Fut: Future, const N: usize> FusedFuture for JoinArray<Fut, N> {
+    fn is_terminated(&self) -> bool {
+        self.elems.iter().all(|elem| elem.is_terminated())
+    }
+}
@@ -0,0 +1,20 @@
+mod array;
+
+pub use array::JoinArray;
+
+use core::future::Future;
+
+/// Wait for multiple futures to complete.
+///
+/// Awaits multiple futures simultaneously, returning the output of each once
+/// every future has completed.
+pub trait Join {
+    /// The resulting output type.
+    type Output;
+
+    /// The [`Future`] returned by the [`join`](Join::join) method.
+    type Future: Future<Output = Self::Output>;
+
+    /// Wait for multiple futures to complete.
+    fn join(self) -> Self::Future;
+}
@@ -0,0 +1,15 @@
+//! Structured concurrency operations for async Rust.
+//!
+//! This crate provides concurrency combinators such as [`Join`] and
+//! [`TryJoin`] that drive several futures at once, built on the
+//! [`MaybeDone`] state machine.
+
+#![cfg_attr(not(test), no_std)]
+
+mod join;
+mod try_join;
+mod utils;
+
+pub use join::{Join, JoinArray};
+pub use try_join::{TryJoin, TryJoinArray};
+pub use utils::{maybe_done, MaybeDone};
@@ -0,0 +1,89 @@
+use core::array;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::future::{FusedFuture, TryFuture};
+
+use super::TryJoin;
+use crate::utils::TryMaybeDone;
+
+impl<Fut, const N: usize> TryJoin for [Fut; N]
+where
+    Fut: TryFuture,
+{
+    type Ok = [Fut::Ok; N];
+    type Error = Fut::Error;
+    type Future = TryJoinArray<Fut, N>;
+
+    fn try_join(self) -> Self::Future {
+        TryJoinArray {
+            elems: self.map(TryMaybeDone::new),
+        }
+    }
+}
+
+/// A future that waits for an array of fallible futures to complete.
+///
+/// This `struct` is created by the [`try_join`](TryJoin::try_join) method on
+/// arrays of [`TryFuture`]s.
+pub struct TryJoinArray<Fut: TryFuture, const N: usize> {
+    elems: [TryMaybeDone<Fut>; N],
+}
+
+impl<Fut: TryFuture, const N: usize> fmt::Debug for TryJoinArray<Fut, N>
+where
+    Fut: fmt::Debug,
+    Fut::Ok: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryJoinArray").field("elems", &self.elems).finish()
+    }
+}
+
+impl<Fut: TryFuture, const N: usize> core::future::Future for TryJoinArray<Fut, N> {
+    type Output = Result<[Fut::Ok; N], Fut::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut all_done = true;
+        let mut error = None;
+
+        let this = unsafe { self.get_unchecked_mut() };
+        for elem in this.elems.iter_mut() {
+            let elem = unsafe { Pin::new_unchecked(elem) };
+            match elem.poll(cx) {
+                Poll::Pending => all_done = false,
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(e)) => {
+                    error = Some(e);
+                    break;
+                }
+            }
+        }
+
+        // The first error short-circuits the whole join: the outputs the
+        // other branches have already produced are dropped right away.
+        if let Some(e) = error {
+            for elem in this.elems.iter_mut() {
+                drop(unsafe { Pin::new_unchecked(elem) }.take_ok());
+            }
+            return Poll::Ready(Err(e));
+        }
+
+        if all_done {
+            let out = array::from_fn(|i| {
+                let elem = unsafe { Pin::new_unchecked(&mut this.elems[i]) };
+                elem.take_ok().unwrap()
+            });
+            Poll::Ready(Ok(out))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<Fut: TryFuture, const N: usize> FusedFuture for TryJoinArray<Fut, N> {
+    fn is_terminated(&self) -> bool {
+        self.elems.iter().all(|elem| elem.is_terminated())
+    }
+}
@@ -0,0 +1,26 @@
+mod array;
+
+pub use array::TryJoinArray;
+
+use core::future::Future;
+
+/// Wait for multiple fallible futures to complete, short-circuiting on the
+/// first error.
+///
+/// Awaits multiple futures simultaneously, returning the success output of
+/// each once every future has completed successfully. As soon as one future
+/// resolves to an error the in-flight outputs are dropped and that error is
+/// returned.
+pub trait TryJoin {
+    /// The success output type.
+    type Ok;
+
+    /// The error output type.
+    type Error;
+
+    /// The [`Future`] returned by the [`try_join`](TryJoin::try_join) method.
+    type Future: Future<Output = Result<Self::Ok, Self::Error>>;
+
+    /// Wait for multiple fallible futures to complete.
+    fn try_join(self) -> Self::Future;
+}
@@ -3,11 +3,17 @@ use core::mem;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
+use futures_core::future::FusedFuture;
 use futures_core::ready;
 
+/// Wraps a future into a `MaybeDone`.
+pub fn maybe_done<Fut: Future>(future: Fut) -> MaybeDone<Fut> {
+    MaybeDone::new(future)
+}
+
 /// A future that may have completed.
 #[derive(Debug)]
-pub(crate) enum MaybeDone<Fut: Future> {
+pub enum MaybeDone<Fut: Future> {
     /// A not-yet-completed future
     Future(Fut),
 
@@ -21,7 +27,7 @@ pub(crate) enum MaybeDone<Fut: Future> {
 
 impl<Fut: Future> MaybeDone<Fut> {
     /// Create a new instance of `MaybeDone`.
-    pub(crate) fn new(future: Fut) -> MaybeDone<Fut> {
+    pub fn new(future: Fut) -> MaybeDone<Fut> {
         Self::Future(future)
     }
 
@@ -30,7 +36,7 @@ impl<Fut: Future> MaybeDone<Fut> {
     /// future has been completed and [`take`](MaybeDone::take)
     /// has not yet been called.
     #[inline]
-    pub(crate) fn output(self: Pin<&Self>) -> Option<&Fut::Output> {
+    pub fn output(self: Pin<&Self>) -> Option<&Fut::Output> {
         let this = self.get_ref();
         match this {
             MaybeDone::Done(res) => Some(res),
@@ -38,10 +44,26 @@ impl<Fut: Future> MaybeDone<Fut> {
         }
     }
 
+    /// Returns an [`Option`] containing a mutable reference to the output of
+    /// the future. The output of this method will be [`Some`] if and only if
+    /// the inner future has been completed and its output has not yet been
+    /// taken with [`take`](MaybeDone::take) or
+    /// [`poll_take`](MaybeDone::poll_take).
+    #[inline]
+    pub fn output_mut(self: Pin<&mut Self>) -> Option<&mut Fut::Output> {
+        unsafe {
+            let this = self.get_unchecked_mut();
+            match this {
+                MaybeDone::Done(res) => Some(res),
+                _ => None,
+            }
+        }
+    }
+
     /// Attempt to take the output of a `MaybeDone` without driving it
     /// towards completion.
     #[inline]
-    pub(crate) fn take(self: Pin<&mut Self>) -> Option<Fut::Output> {
+    pub fn take(self: Pin<&mut Self>) -> Option<Fut::Output> {
         unsafe {
             let this = self.get_unchecked_mut();
             match this {
@@ -55,6 +77,35 @@ impl<Fut: Future> MaybeDone<Fut> {
             }
         }
     }
+
+    /// Drive the inner future and, on completion, extract its output in a
+    /// single pass.
+    ///
+    /// Returns [`Poll::Ready`]`(`[`Some`]`(output))` when the future resolves,
+    /// transitioning the state directly to [`Gone`](MaybeDone::Gone);
+    /// [`Poll::Ready`]`(`[`None`]`)` if the output has already been taken, and
+    /// [`Poll::Pending`] while the future is still running. This fuses the
+    /// poll-to-completion and [`take`](MaybeDone::take) steps so drivers that
+    /// sweep many `MaybeDone`s per wakeup avoid a second scan.
+    #[inline]
+    pub fn poll_take(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Fut::Output>> {
+        let res = unsafe {
+            match Pin::as_mut(&mut self).get_unchecked_mut() {
+                MaybeDone::Future(a) => ready!(Pin::new_unchecked(a).poll(cx)),
+                MaybeDone::Done(_) => {
+                    let this = self.get_unchecked_mut();
+                    if let MaybeDone::Done(output) = mem::replace(this, MaybeDone::Gone) {
+                        return Poll::Ready(Some(output));
+                    } else {
+                        unreachable!()
+                    }
+                }
+                MaybeDone::Gone => return Poll::Ready(None),
+            }
+        };
+        self.set(MaybeDone::Gone);
+        Poll::Ready(Some(res))
+    }
 }
 
 impl<Fut: Future> Future for MaybeDone<Fut> {
@@ -72,3 +123,12 @@ impl<Fut: Future> Future for MaybeDone<Fut> {
         Poll::Ready(())
     }
 }
+
+impl<Fut: Future> FusedFuture for MaybeDone<Fut> {
+    fn is_terminated(&self) -> bool {
+        match self {
+            MaybeDone::Future(_) => false,
+            MaybeDone::Done(_) | MaybeDone::Gone => true,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod maybe_done;
+pub(crate) mod try_maybe_done;
+
+pub use maybe_done::{maybe_done, MaybeDone};
+pub(crate) use try_maybe_done::TryMaybeDone;
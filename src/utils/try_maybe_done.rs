@@ -0,0 +1,82 @@
+use core::future::Future;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::future::{FusedFuture, TryFuture};
+use futures_core::ready;
+
+/// A fallible future that may have completed.
+///
+/// Unlike [`MaybeDone`](super::MaybeDone), this stores only the success value
+/// of the inner future so that a combinator such as `try_join` can drop the
+/// in-flight outputs and short-circuit as soon as the first error is observed.
+#[derive(Debug)]
+pub(crate) enum TryMaybeDone<Fut: TryFuture> {
+    /// A not-yet-completed future
+    Future(Fut),
+
+    /// The success output of the completed future
+    Done(Fut::Ok),
+
+    /// The empty variant after the result of a [`TryMaybeDone`] has been
+    /// taken using the [`take_ok`](TryMaybeDone::take_ok) method, or after the
+    /// inner future resolved to an error.
+    Gone,
+}
+
+impl<Fut: TryFuture> TryMaybeDone<Fut> {
+    /// Create a new instance of `TryMaybeDone`.
+    pub(crate) fn new(future: Fut) -> TryMaybeDone<Fut> {
+        Self::Future(future)
+    }
+
+    /// Attempt to take the success output of a `TryMaybeDone` without driving
+    /// it towards completion.
+    #[inline]
+    pub(crate) fn take_ok(self: Pin<&mut Self>) -> Option<Fut::Ok> {
+        unsafe {
+            let this = self.get_unchecked_mut();
+            match this {
+                TryMaybeDone::Done(_) => {}
+                TryMaybeDone::Future(_) | TryMaybeDone::Gone => return None,
+            };
+            if let TryMaybeDone::Done(output) = mem::replace(this, TryMaybeDone::Gone) {
+                Some(output)
+            } else {
+                unreachable!()
+            }
+        }
+    }
+}
+
+impl<Fut: TryFuture> Future for TryMaybeDone<Fut> {
+    type Output = Result<(), Fut::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let res = unsafe {
+            match Pin::as_mut(&mut self).get_unchecked_mut() {
+                TryMaybeDone::Future(a) => match ready!(Pin::new_unchecked(a).try_poll(cx)) {
+                    Ok(res) => res,
+                    Err(e) => {
+                        self.set(TryMaybeDone::Gone);
+                        return Poll::Ready(Err(e));
+                    }
+                },
+                TryMaybeDone::Done(_) => return Poll::Ready(Ok(())),
+                TryMaybeDone::Gone => panic!("TryMaybeDone polled after value taken"),
+            }
+        };
+        self.set(TryMaybeDone::Done(res));
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<Fut: TryFuture> FusedFuture for TryMaybeDone<Fut> {
+    fn is_terminated(&self) -> bool {
+        match self {
+            TryMaybeDone::Future(_) => false,
+            TryMaybeDone::Done(_) | TryMaybeDone::Gone => true,
+        }
+    }
+}